@@ -1,24 +1,364 @@
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use std::{fs::read, io::Cursor, process::Stdio};
 
 use anyhow::bail;
+use bollard::container::{
+    Config as ContainerConfig, CreateContainerOptions, DownloadFromContainerOptions,
+    RemoveContainerOptions, UploadToContainerOptions,
+};
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use bollard::image::{ExportImageOptions, ImportImageOptions, RemoveImageOptions};
+use bollard::models::HostConfig;
+use bollard::volume::{CreateVolumeOptions, RemoveVolumeOptions};
+use bollard::Docker;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use futures_util::StreamExt;
 use lib::config::Config;
 use skim::{
     prelude::{SkimItemReader, SkimOptionsBuilder},
     Skim,
 };
+use tar::{Archive, Builder};
+use tokio::io::AsyncWriteExt;
 
 use crate::{DockerAction, DockerCommand};
 
+/// Default minimum age for `docker prune` when `--date` is not given: keep
+/// anything saved within the last two days.
+const DEFAULT_PRUNE_AGE_DAYS: u64 = 2;
+
+/// Name of the persistent volume used to shuttle archives to a remote
+/// docker engine, and the path it's mounted at in the transfer helper.
+const TRANSFER_VOLUME_NAME: &str = "nfde-transfer";
+const TRANSFER_MOUNT_PATH: &str = "/transfer";
+
+/// A docker-cli image so the transfer helper can run `docker save`/`docker
+/// load` against the volume itself, talking to the same daemon through the
+/// bind-mounted socket.
+const TRANSFER_HELPER_IMAGE: &str = "docker:cli";
+const DOCKER_SOCKET_PATH: &str = "/var/run/docker.sock";
+
 fn config() -> Config {
     lib::config::get_config().unwrap()
 }
 
+/// Whether to shell out to the `docker` CLI instead of talking to the daemon
+/// directly. Defaults to `false` (bollard) so callers get typed errors
+/// instead of bare exit-status checks; set to `true` for environments where
+/// only the CLI is available (e.g. no socket access to the daemon).
+fn use_docker_cli() -> bool {
+    config().use_docker_cli()
+}
+
+fn tokio_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Runtime::new().expect("Failed to start async runtime")
+}
+
+fn connect_docker() -> anyhow::Result<Docker> {
+    Docker::connect_with_local_defaults()
+        .map_err(|e| anyhow::anyhow!("Could not connect to docker daemon: {e}"))
+}
+
+async fn create_transfer_volume() -> anyhow::Result<()> {
+    let docker = connect_docker()?;
+    ensure_transfer_volume(&docker).await?;
+
+    println!("Created transfer volume: {TRANSFER_VOLUME_NAME}");
+
+    Ok(())
+}
+
+async fn remove_transfer_volume() -> anyhow::Result<()> {
+    let docker = connect_docker()?;
+
+    docker
+        .remove_volume(TRANSFER_VOLUME_NAME, Some(RemoveVolumeOptions { force: true }))
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not remove transfer volume: {e}"))?;
+
+    println!("Removed transfer volume: {TRANSFER_VOLUME_NAME}");
+
+    Ok(())
+}
+
+async fn ensure_transfer_volume(docker: &Docker) -> anyhow::Result<()> {
+    let options = CreateVolumeOptions {
+        name: TRANSFER_VOLUME_NAME,
+        ..Default::default()
+    };
+
+    docker
+        .create_volume(options)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not create transfer volume: {e}"))?;
+
+    Ok(())
+}
+
+/// Whether `DOCKER_HOST` points at a daemon that isn't reachable over the
+/// local filesystem (e.g. `tcp://` or `ssh://`), meaning archive paths on
+/// this machine aren't visible to the daemon and need the transfer volume.
+fn is_remote_engine() -> bool {
+    std::env::var("DOCKER_HOST")
+        .map(|host| !host.is_empty() && !host.starts_with("unix://"))
+        .unwrap_or(false)
+}
+
+/// Starts a short-lived helper container with the transfer volume mounted at
+/// `TRANSFER_MOUNT_PATH` and the docker socket bind-mounted in, so it can run
+/// `docker save`/`docker load` against the daemon on the engine's own side.
+/// Borrowed from the data-volume technique `cross` uses to reach build
+/// contexts on a remote docker engine. Docker assigns the container a unique
+/// name (we don't pass one) so a prior run's leftover container never blocks
+/// a new one.
+async fn spawn_transfer_helper(docker: &Docker) -> anyhow::Result<String> {
+    ensure_transfer_volume(docker).await?;
+
+    let options = CreateContainerOptions {
+        name: "",
+        platform: None,
+    };
+
+    let container_config = ContainerConfig {
+        image: Some(TRANSFER_HELPER_IMAGE.to_string()),
+        cmd: Some(vec!["sleep".to_string(), "infinity".to_string()]),
+        host_config: Some(HostConfig {
+            binds: Some(vec![
+                format!("{TRANSFER_VOLUME_NAME}:{TRANSFER_MOUNT_PATH}"),
+                format!("{DOCKER_SOCKET_PATH}:{DOCKER_SOCKET_PATH}"),
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let container = docker
+        .create_container(Some(options), container_config)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not create transfer helper container: {e}"))?;
+
+    docker
+        .start_container::<String>(&container.id, None)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not start transfer helper container: {e}"))?;
+
+    Ok(container.id)
+}
+
+async fn teardown_transfer_helper(docker: &Docker, container_id: &str) -> anyhow::Result<()> {
+    docker
+        .remove_container(
+            container_id,
+            Some(RemoveContainerOptions {
+                force: true,
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not remove transfer helper container: {e}"))?;
+
+    Ok(())
+}
+
+/// Runs `cmd` inside the transfer helper (e.g. `docker save`/`docker load`
+/// against an in-volume path) and fails if it exits non-zero.
+async fn exec_in_transfer_helper(
+    docker: &Docker,
+    container_id: &str,
+    cmd: Vec<String>,
+) -> anyhow::Result<()> {
+    let exec = docker
+        .create_exec(
+            container_id,
+            CreateExecOptions {
+                cmd: Some(cmd),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not create exec in transfer helper: {e}"))?;
+
+    if let StartExecResults::Attached { mut output, .. } = docker
+        .start_exec(&exec.id, None)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not start exec in transfer helper: {e}"))?
+    {
+        while let Some(chunk) = output.next().await {
+            chunk.map_err(|e| anyhow::anyhow!("Transfer helper command failed: {e}"))?;
+        }
+    }
+
+    let inspect = docker
+        .inspect_exec(&exec.id)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not inspect transfer helper exec: {e}"))?;
+
+    if inspect.exit_code.unwrap_or(1) != 0 {
+        bail!("Transfer helper command exited with a non-zero status");
+    }
+
+    Ok(())
+}
+
+/// Uploads `local_path` into the transfer helper's mount of the volume as
+/// `file_name`, so a `docker load` run inside the helper can see it.
+async fn upload_archive_to_transfer_volume(
+    docker: &Docker,
+    container_id: &str,
+    local_path: &Path,
+    file_name: &str,
+) -> anyhow::Result<()> {
+    let mut builder = Builder::new(Vec::new());
+    builder.append_path_with_name(local_path, file_name)?;
+    let tar_bytes = builder.into_inner()?;
+
+    docker
+        .upload_to_container(
+            container_id,
+            Some(UploadToContainerOptions {
+                path: TRANSFER_MOUNT_PATH.to_string(),
+                ..Default::default()
+            }),
+            tar_bytes.into(),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not copy archive into transfer volume: {e}"))?;
+
+    Ok(())
+}
+
+/// Downloads `file_name` back out of the transfer helper's mount of the
+/// volume (e.g. after a `docker save` run inside the helper wrote it there).
+async fn download_archive_from_transfer_volume(
+    docker: &Docker,
+    container_id: &str,
+    file_name: &str,
+) -> anyhow::Result<Vec<u8>> {
+    let remote_path = format!("{TRANSFER_MOUNT_PATH}/{file_name}");
+    let mut stream = docker.download_from_container(
+        container_id,
+        Some(DownloadFromContainerOptions { path: remote_path }),
+    );
+
+    let mut tar_bytes = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        tar_bytes.extend_from_slice(
+            &chunk.map_err(|e| anyhow::anyhow!("Could not copy archive from transfer volume: {e}"))?,
+        );
+    }
+
+    let mut archive = Archive::new(Cursor::new(tar_bytes));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.into_owned();
+
+        if entry_path.file_name().and_then(|n| n.to_str()) == Some(file_name) {
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+            return Ok(contents);
+        }
+    }
+
+    bail!("Archive entry {file_name} was not found in the transfer volume download")
+}
+
+/// Saves the configured image by running `docker save` against an in-volume
+/// path inside the transfer helper, then pulling the resulting archive back
+/// out to `image_path`. Used when the daemon is remote and the local
+/// `image_folder` path isn't visible to it.
+async fn save_via_transfer_volume(
+    docker: &Docker,
+    image_path: &Path,
+    compress: bool,
+) -> anyhow::Result<()> {
+    let container_id = spawn_transfer_helper(docker).await?;
+    let remote_file_name = "image.tar";
+    let remote_path = format!("{TRANSFER_MOUNT_PATH}/{remote_file_name}");
+
+    let result: anyhow::Result<Vec<u8>> = async {
+        exec_in_transfer_helper(
+            docker,
+            &container_id,
+            vec![
+                "docker".to_string(),
+                "save".to_string(),
+                "-o".to_string(),
+                remote_path,
+                config().api_image_name.clone(),
+            ],
+        )
+        .await?;
+
+        download_archive_from_transfer_volume(docker, &container_id, remote_file_name).await
+    }
+    .await;
+
+    teardown_transfer_helper(docker, &container_id).await?;
+    let tar_bytes = result?;
+
+    if compress {
+        let file = std::fs::File::create(image_path)?;
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(&tar_bytes)?;
+        encoder.finish()?;
+    } else {
+        std::fs::write(image_path, &tar_bytes)?;
+    }
+
+    println!("Synced saved image through remote transfer volume '{TRANSFER_VOLUME_NAME}'");
+
+    Ok(())
+}
+
+/// Uploads `image_path` into the transfer volume and runs `docker load`
+/// against that in-volume path inside the transfer helper. Used when the
+/// daemon is remote and the local `image_folder` path isn't visible to it.
+async fn load_via_transfer_volume(docker: &Docker, image_path: &Path) -> anyhow::Result<()> {
+    let container_id = spawn_transfer_helper(docker).await?;
+    let file_name = archive_file_name(image_path);
+    let remote_path = format!("{TRANSFER_MOUNT_PATH}/{file_name}");
+
+    let result: anyhow::Result<()> = async {
+        upload_archive_to_transfer_volume(docker, &container_id, image_path, &file_name).await?;
+
+        exec_in_transfer_helper(
+            docker,
+            &container_id,
+            vec![
+                "docker".to_string(),
+                "load".to_string(),
+                "-i".to_string(),
+                remote_path,
+            ],
+        )
+        .await
+    }
+    .await;
+
+    teardown_transfer_helper(docker, &container_id).await?;
+    result?;
+
+    println!("Loaded docker image through remote transfer volume '{TRANSFER_VOLUME_NAME}'");
+
+    Ok(())
+}
+
+fn archive_file_name(path: &Path) -> String {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
 pub fn handle_docker_command(docker_command: DockerCommand) -> anyhow::Result<()> {
     match docker_command.docker_action {
         DockerAction::Save(docker_save_command) => {
-            save(docker_save_command.name)?;
+            save(docker_save_command.name, docker_save_command.compress)?;
         }
         DockerAction::Load(docker_load_command) => {
             load(docker_load_command.name)?;
@@ -26,36 +366,43 @@ pub fn handle_docker_command(docker_command: DockerCommand) -> anyhow::Result<()
         DockerAction::Remove(docker_remove_command) => {
             remove(docker_remove_command.name)?;
         }
+        DockerAction::Prune(docker_prune_command) => {
+            prune(
+                docker_prune_command.date,
+                docker_prune_command.repository,
+                docker_prune_command.tags,
+                docker_prune_command.dry_run,
+                docker_prune_command.force,
+            )?;
+        }
+        DockerAction::List(docker_list_command) => {
+            list(docker_list_command.sort, docker_list_command.repository)?;
+        }
+        DockerAction::VolumeCreate => {
+            tokio_runtime().block_on(create_transfer_volume())?;
+        }
+        DockerAction::VolumeRemove => {
+            tokio_runtime().block_on(remove_transfer_volume())?;
+        }
     };
 
     Ok(())
 }
 
-fn save(name: Option<String>) -> anyhow::Result<()> {
+fn save(name: Option<String>, compress: bool) -> anyhow::Result<()> {
     match name {
         Some(name) => {
-            let image_path = Path::new(&config().image_folder()).join(format!("{}.tar", name));
+            let compress = should_compress(compress);
+            let extension = if compress { "tar.gz" } else { "tar" };
+            let image_path =
+                Path::new(&config().image_folder()).join(format!("{}.{}", name, extension));
 
             println!("Saving docker image to {}", &image_path.display());
 
-            let ran = {
-                let mut cmd = ::std::process::Command::new("docker");
-                cmd.arg("save");
-                cmd.arg("-o");
-                cmd.arg(image_path);
-                cmd.arg(&config().api_image_name);
-                cmd
-            }
-            .status()
-            .unwrap()
-            .success();
-
-            if ran {
-                println!("Successfully saved docker image");
-
-                Ok(())
+            if use_docker_cli() {
+                save_via_cli(&image_path, compress)
             } else {
-                Err(anyhow::anyhow!("Could not save docker image"))
+                tokio_runtime().block_on(save_via_api(&image_path, compress))
             }
         }
         None => {
@@ -64,13 +411,114 @@ fn save(name: Option<String>) -> anyhow::Result<()> {
     }
 }
 
+/// Whether a saved image should be gzip-compressed: an explicit `--compress`
+/// flag always wins, otherwise fall back to the configured default.
+fn should_compress(flag: bool) -> bool {
+    flag || config().compress_images()
+}
+
+fn save_via_cli(image_path: &Path, compress: bool) -> anyhow::Result<()> {
+    if !compress {
+        let ran = {
+            let mut cmd = ::std::process::Command::new("docker");
+            cmd.arg("save");
+            cmd.arg("-o");
+            cmd.arg(image_path);
+            cmd.arg(&config().api_image_name);
+            cmd
+        }
+        .status()
+        .unwrap()
+        .success();
+
+        return if ran {
+            println!("Successfully saved docker image");
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Could not save docker image"))
+        };
+    }
+
+    let mut child = ::std::process::Command::new("docker")
+        .arg("save")
+        .arg(&config().api_image_name)
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn docker command");
+
+    let mut stdout = child.stdout.take().expect("Failed to capture docker stdout");
+    let file = std::fs::File::create(image_path)?;
+    let mut encoder = GzEncoder::new(file, Compression::default());
+
+    std::io::copy(&mut stdout, &mut encoder)?;
+    encoder.finish()?;
+
+    let ran = child
+        .wait()
+        .expect("Failed to wait for docker command")
+        .success();
+
+    if ran {
+        println!("Successfully saved docker image");
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("Could not save docker image"))
+    }
+}
+
+async fn save_via_api(image_path: &Path, compress: bool) -> anyhow::Result<()> {
+    let docker = connect_docker()?;
+
+    if is_remote_engine() {
+        return save_via_transfer_volume(&docker, image_path, compress).await;
+    }
+
+    let options = ExportImageOptions {
+        names: config().api_image_name.clone(),
+    };
+
+    let mut stream = docker.export_image(&options);
+
+    if compress {
+        let file = std::fs::File::create(image_path)?;
+        let mut encoder = GzEncoder::new(file, Compression::default());
+
+        while let Some(chunk) = stream.next().await {
+            let bytes = chunk.map_err(|e| anyhow::anyhow!("Could not export docker image: {e}"))?;
+            encoder.write_all(&bytes)?;
+        }
+
+        encoder.finish()?;
+    } else {
+        let mut file = tokio::fs::File::create(image_path).await?;
+
+        while let Some(chunk) = stream.next().await {
+            let bytes =
+                chunk.map_err(|e| anyhow::anyhow!("Could not export docker image: {e}"))?;
+            file.write_all(&bytes).await?;
+        }
+
+        file.flush().await?;
+    }
+
+    println!("Successfully saved docker image");
+
+    Ok(())
+}
+
 fn load(name: Option<String>) -> anyhow::Result<()> {
     let image_path = determine_image_path(name)?;
 
-    let image_data = read(&image_path).expect("Failed to read image file");
-
     println!("Loading docker image {}", &image_path.display());
 
+    if use_docker_cli() {
+        load_via_cli(&image_path)
+    } else {
+        tokio_runtime().block_on(load_via_api(&image_path))
+    }
+}
+
+fn load_via_cli(image_path: &Path) -> anyhow::Result<()> {
     let mut child = ::std::process::Command::new("docker")
         .arg("load")
         .stdin(Stdio::piped())
@@ -80,9 +528,16 @@ fn load(name: Option<String>) -> anyhow::Result<()> {
         .expect("Failed to spawn docker command");
 
     if let Some(mut stdin) = child.stdin.take() {
-        stdin
-            .write_all(&image_data)
-            .expect("Failed to write image data to stdin");
+        if is_gzip_archive(image_path) {
+            let file = std::fs::File::open(image_path).expect("Failed to open image file");
+            let mut decoder = GzDecoder::new(file);
+            std::io::copy(&mut decoder, &mut stdin).expect("Failed to stream image data to stdin");
+        } else {
+            let image_data = read(image_path).expect("Failed to read image file");
+            stdin
+                .write_all(&image_data)
+                .expect("Failed to write image data to stdin");
+        }
         stdin.flush().expect("Failed to flush stdin");
     }
 
@@ -98,12 +553,53 @@ fn load(name: Option<String>) -> anyhow::Result<()> {
     }
 }
 
+async fn load_via_api(image_path: &Path) -> anyhow::Result<()> {
+    let docker = connect_docker()?;
+
+    if is_remote_engine() {
+        return load_via_transfer_volume(&docker, image_path).await;
+    }
+
+    let body = if is_gzip_archive(image_path) {
+        let file = std::fs::File::open(image_path)?;
+        let mut decoder = GzDecoder::new(file);
+        let mut decompressed = Vec::new();
+        decoder.read_to_end(&mut decompressed)?;
+
+        hyper::Body::from(decompressed)
+    } else {
+        let file = tokio::fs::File::open(image_path).await?;
+        let byte_stream =
+            tokio_util::codec::FramedRead::new(file, tokio_util::codec::BytesCodec::new())
+                .map(|res| res.map(|bytes| bytes.freeze()));
+
+        hyper::Body::wrap_stream(byte_stream)
+    };
+
+    let options = ImportImageOptions { quiet: false };
+    let mut stream = docker.import_image(options, body, None);
+
+    while let Some(info) = stream.next().await {
+        info.map_err(|e| anyhow::anyhow!("Could not load docker image: {e}"))?;
+    }
+
+    Ok(())
+}
+
 fn remove(name: Option<String>) -> anyhow::Result<()> {
     let image_path = determine_image_path(name)?;
 
+    if use_docker_cli() {
+        remove_via_cli(&image_path)
+    } else {
+        tokio_runtime().block_on(remove_via_api(&image_path))
+    }
+}
+
+fn remove_via_cli(image_path: &Path) -> anyhow::Result<()> {
     let ran = {
         let mut cmd = ::std::process::Command::new("rm");
-        cmd.arg(&image_path);
+        cmd.arg(image_path);
         cmd
     }
     .status()
@@ -118,9 +614,261 @@ fn remove(name: Option<String>) -> anyhow::Result<()> {
     }
 }
 
+async fn remove_via_api(image_path: &Path) -> anyhow::Result<()> {
+    let docker = connect_docker()?;
+
+    docker
+        .remove_image(&config().api_image_name, Some(RemoveImageOptions::default()), None)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not remove docker image from daemon: {e}"))?;
+
+    std::fs::remove_file(image_path)?;
+
+    println!("Removed image: {}", image_path.display());
+
+    Ok(())
+}
+
+fn prune(
+    date: Option<String>,
+    repository: Option<String>,
+    tags: Vec<String>,
+    dry_run: bool,
+    force: bool,
+) -> anyhow::Result<()> {
+    let filter = parse_date_filter(date.as_deref())?;
+    let image_folder = config().image_folder();
+
+    let mut candidates = Vec::new();
+
+    for entry in std::fs::read_dir(&image_folder)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if !is_archive_path(&path) {
+            continue;
+        }
+
+        let metadata = entry.metadata()?;
+
+        if !filter.matches(metadata.modified()?) {
+            continue;
+        }
+
+        let stem = strip_archive_extension(&path);
+
+        if let Some(repository) = &repository {
+            if !stem.contains(repository.as_str()) {
+                continue;
+            }
+        }
+
+        if !tags.is_empty() && !tags.iter().any(|tag| stem.contains(tag.as_str())) {
+            continue;
+        }
+
+        candidates.push((path, metadata.len()));
+    }
+
+    if candidates.is_empty() {
+        println!("No archives matched the prune criteria");
+        return Ok(());
+    }
+
+    let reclaimed: u64 = candidates.iter().map(|(_, size)| size).sum();
+
+    for (path, size) in &candidates {
+        println!("{} ({})", path.display(), format_size(*size));
+    }
+
+    println!(
+        "{} archive(s) match, reclaimable space: {}",
+        candidates.len(),
+        format_size(reclaimed)
+    );
+
+    if dry_run {
+        println!("Dry run: no files were deleted");
+        return Ok(());
+    }
+
+    if !force {
+        bail!("Refusing to delete archives without --force (use --dry-run to preview)");
+    }
+
+    for (path, _) in &candidates {
+        std::fs::remove_file(path)?;
+    }
+
+    println!(
+        "Deleted {} archive(s), reclaimed {}",
+        candidates.len(),
+        format_size(reclaimed)
+    );
+
+    Ok(())
+}
+
+enum DateFilter {
+    MinAge(Duration),
+    Range(NaiveDateTime, NaiveDateTime),
+}
+
+impl DateFilter {
+    fn matches(&self, modified: SystemTime) -> bool {
+        match self {
+            DateFilter::MinAge(min_age) => SystemTime::now()
+                .duration_since(modified)
+                .map(|age| age >= *min_age)
+                .unwrap_or(false),
+            DateFilter::Range(from, to) => {
+                let modified: DateTime<Utc> = modified.into();
+                let modified = modified.naive_utc();
+                modified >= *from && modified <= *to
+            }
+        }
+    }
+}
+
+fn parse_date_filter(date: Option<&str>) -> anyhow::Result<DateFilter> {
+    match date {
+        None => Ok(DateFilter::MinAge(Duration::from_secs(
+            DEFAULT_PRUNE_AGE_DAYS * 24 * 60 * 60,
+        ))),
+        Some(date) => match date.split_once('|') {
+            Some((from, to)) => Ok(DateFilter::Range(parse_datetime(from)?, parse_datetime(to)?)),
+            None => {
+                let days: u64 = date
+                    .trim_end_matches('d')
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Could not parse --date value: {date}"))?;
+
+                Ok(DateFilter::MinAge(Duration::from_secs(days * 24 * 60 * 60)))
+            }
+        },
+    }
+}
+
+fn parse_datetime(value: &str) -> anyhow::Result<NaiveDateTime> {
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(datetime);
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+        .map_err(|_| anyhow::anyhow!("Could not parse date: {value}"))
+}
+
+fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    let bytes = bytes as f64;
+
+    if bytes >= GB {
+        format!("{:.2} GB", bytes / GB)
+    } else if bytes >= MB {
+        format!("{:.2} MB", bytes / MB)
+    } else if bytes >= KB {
+        format!("{:.2} KB", bytes / KB)
+    } else {
+        format!("{} B", bytes as u64)
+    }
+}
+
+fn list(sort: Option<String>, repository: Option<String>) -> anyhow::Result<()> {
+    let image_folder = config().image_folder();
+
+    let mut archives = Vec::new();
+
+    for entry in std::fs::read_dir(&image_folder)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if !is_archive_path(&path) {
+            continue;
+        }
+
+        let name = strip_archive_extension(&path);
+
+        if let Some(repository) = &repository {
+            if !name.contains(repository.as_str()) {
+                continue;
+            }
+        }
+
+        let metadata = entry.metadata()?;
+        archives.push((name, metadata.len(), metadata.modified()?));
+    }
+
+    if archives.is_empty() {
+        println!("No saved archives found in {}", image_folder);
+        return Ok(());
+    }
+
+    sort_archives(&mut archives, sort.as_deref())?;
+
+    println!("{:<40} {:>10} {:>12}", "NAME", "SIZE", "AGE");
+
+    for (name, size, modified) in &archives {
+        println!(
+            "{:<40} {:>10} {:>12}",
+            name,
+            format_size(*size),
+            format_age(*modified)
+        );
+    }
+
+    Ok(())
+}
+
+/// Sorts `docker list` rows in place by the requested key. `size` and `date`
+/// sort descending (biggest/newest first), `name` (and the default) sorts
+/// ascending.
+fn sort_archives(
+    archives: &mut [(String, u64, SystemTime)],
+    sort: Option<&str>,
+) -> anyhow::Result<()> {
+    match sort {
+        Some("size") => archives.sort_by(|a, b| b.1.cmp(&a.1)),
+        Some("date") => archives.sort_by(|a, b| b.2.cmp(&a.2)),
+        Some("name") | None => archives.sort_by(|a, b| a.0.cmp(&b.0)),
+        Some(other) => bail!("Unknown sort key: {other} (expected size, date or name)"),
+    }
+
+    Ok(())
+}
+
+fn is_archive_path(path: &Path) -> bool {
+    if !path.is_file() {
+        return false;
+    }
+
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    name.ends_with(".tar") || name.ends_with(".tar.gz")
+}
+
+fn format_age(modified: SystemTime) -> String {
+    let elapsed = SystemTime::now().duration_since(modified).unwrap_or_default();
+    let days = elapsed.as_secs() / (24 * 60 * 60);
+
+    if days > 0 {
+        return format!("{days}d ago");
+    }
+
+    let hours = elapsed.as_secs() / (60 * 60);
+
+    if hours > 0 {
+        return format!("{hours}h ago");
+    }
+
+    format!("{}m ago", elapsed.as_secs() / 60)
+}
+
 fn determine_image_path(name: Option<String>) -> anyhow::Result<PathBuf> {
     let image_path = match name {
-        Some(name) => Path::new(&config().image_folder()).join(format!("{}.tar", name)),
+        Some(name) => resolve_named_image_path(&name),
         None => {
             let selected_file = select_image();
             match selected_file {
@@ -137,14 +885,42 @@ fn determine_image_path(name: Option<String>) -> anyhow::Result<PathBuf> {
         bail!("File does not exist: {}", &image_path.display());
     }
 
-    //check if file extension is sql
-    if !&image_path.display().to_string().ends_with(".tar") {
-        bail!("File is not a tar file: {}", &image_path.display());
+    //check if file extension is tar or tar.gz
+    let path_str = image_path.display().to_string();
+    if !path_str.ends_with(".tar") && !path_str.ends_with(".tar.gz") {
+        bail!("File is not a tar archive: {}", &image_path.display());
     }
 
     Ok(image_path)
 }
 
+/// Resolves a bare image name to its archive on disk, preferring a
+/// compressed `.tar.gz` archive over an uncompressed `.tar` one.
+fn resolve_named_image_path(name: &str) -> PathBuf {
+    let folder = config().image_folder();
+    let gz_path = Path::new(&folder).join(format!("{}.tar.gz", name));
+
+    if gz_path.exists() {
+        return gz_path;
+    }
+
+    Path::new(&folder).join(format!("{}.tar", name))
+}
+
+fn is_gzip_archive(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()) == Some("gz")
+}
+
+fn strip_archive_extension(path: &Path) -> String {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+
+    file_name
+        .strip_suffix(".tar.gz")
+        .or_else(|| file_name.strip_suffix(".tar"))
+        .unwrap_or(file_name)
+        .to_string()
+}
+
 fn select_image() -> anyhow::Result<String> {
     let options = SkimOptionsBuilder::default()
         .height(Some("100%"))
@@ -156,12 +932,8 @@ fn select_image() -> anyhow::Result<String> {
 
     let joined_by_newline = files_in_folder
         .filter(|file| {
-            file.as_ref()
-                .unwrap()
-                .file_name()
-                .into_string()
-                .unwrap()
-                .ends_with(".tar")
+            let name = file.as_ref().unwrap().file_name().into_string().unwrap();
+            name.ends_with(".tar") || name.ends_with(".tar.gz")
         })
         .map(|file| file.unwrap().file_name().into_string().unwrap())
         .collect::<Vec<String>>()
@@ -188,3 +960,106 @@ fn select_image() -> anyhow::Result<String> {
 
     Ok(selected_filename)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    #[test]
+    fn parse_date_filter_defaults_to_two_days() {
+        let filter = parse_date_filter(None).unwrap();
+
+        assert!(matches!(
+            filter,
+            DateFilter::MinAge(age) if age == Duration::from_secs(2 * 24 * 60 * 60)
+        ));
+    }
+
+    #[test]
+    fn parse_date_filter_parses_a_day_count() {
+        let filter = parse_date_filter(Some("5d")).unwrap();
+
+        assert!(matches!(
+            filter,
+            DateFilter::MinAge(age) if age == Duration::from_secs(5 * 24 * 60 * 60)
+        ));
+    }
+
+    #[test]
+    fn parse_date_filter_parses_a_from_to_range() {
+        let filter = parse_date_filter(Some("2024-01-01|2024-01-31T12:00:00")).unwrap();
+
+        let (from, to) = match filter {
+            DateFilter::Range(from, to) => (from, to),
+            DateFilter::MinAge(_) => panic!("expected a range filter"),
+        };
+
+        assert_eq!(from.to_string(), "2024-01-01 00:00:00");
+        assert_eq!(to.to_string(), "2024-01-31 12:00:00");
+    }
+
+    #[test]
+    fn parse_date_filter_rejects_garbage() {
+        assert!(parse_date_filter(Some("not-a-date")).is_err());
+    }
+
+    #[test]
+    fn format_size_picks_the_largest_fitting_unit() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2 * 1024), "2.00 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.00 MB");
+        assert_eq!(format_size(4 * 1024 * 1024 * 1024), "4.00 GB");
+    }
+
+    fn archives_fixture() -> Vec<(String, u64, SystemTime)> {
+        vec![
+            ("beta".to_string(), 300, UNIX_EPOCH + Duration::from_secs(200)),
+            ("alpha".to_string(), 100, UNIX_EPOCH + Duration::from_secs(300)),
+            ("gamma".to_string(), 200, UNIX_EPOCH + Duration::from_secs(100)),
+        ]
+    }
+
+    #[test]
+    fn sort_archives_by_name_ascending() {
+        let mut archives = archives_fixture();
+        sort_archives(&mut archives, Some("name")).unwrap();
+
+        let names: Vec<_> = archives.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn sort_archives_by_size_descending() {
+        let mut archives = archives_fixture();
+        sort_archives(&mut archives, Some("size")).unwrap();
+
+        let sizes: Vec<_> = archives.iter().map(|(_, size, _)| *size).collect();
+        assert_eq!(sizes, [300, 200, 100]);
+    }
+
+    #[test]
+    fn sort_archives_by_date_descending() {
+        let mut archives = archives_fixture();
+        sort_archives(&mut archives, Some("date")).unwrap();
+
+        let names: Vec<_> = archives.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn sort_archives_defaults_to_name_when_unspecified() {
+        let mut archives = archives_fixture();
+        sort_archives(&mut archives, None).unwrap();
+
+        let names: Vec<_> = archives.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn sort_archives_rejects_unknown_key() {
+        let mut archives = archives_fixture();
+        assert!(sort_archives(&mut archives, Some("bogus")).is_err());
+    }
+}